@@ -3,6 +3,7 @@ use crate::common::io;
 
 const CYCLES_PER_ROW: usize = 40;
 const SCREEN_ROWS: usize = 6;
+const SPRITE_WIDTH: i32 = 3;
 
 pub fn parse_instructions(filename: &str) -> Vec<Instruction> {
     let lines = io::read_file_as_vector(filename).expect("Could not read file");
@@ -12,67 +13,37 @@ pub fn parse_instructions(filename: &str) -> Vec<Instruction> {
         .collect()
 }
 
-pub fn get_sum_of_signal_strengths(instructions: &Vec<Instruction>) -> i32 {
-    let mut cycles_to_check = vec![20, 60, 100, 140, 180, 220];
-    let mut cycles_values: Vec<i32> = Vec::with_capacity(cycles_to_check.len());
-
-    instructions
-        .iter()
-        .fold((1, 0), |(regx, cycles): (i32, i32), instruction| {
-            if cycles_to_check.is_empty() {
-                return (regx, cycles);
-            }
-
-            for i in 1..=instruction.cycles() {
-                let current_cycle = cycles + i;
-
-                if current_cycle == cycles_to_check[0] {
-                    let strength = regx * cycles_to_check[0];
-                    cycles_values.push(strength);
-                    cycles_to_check = cycles_to_check[1..].to_vec();
-                    break;
-                }
-            }
-
-            (regx + instruction.value(), cycles + instruction.cycles())
-        });
-
-    cycles_values.iter().sum()
+pub fn get_sum_of_signal_strengths(instructions: &[Instruction], cycles_to_sample: &[usize]) -> i32 {
+    CpuIterator::new(instructions)
+        .filter(|(cycle, _)| cycles_to_sample.contains(cycle))
+        .map(|(cycle, regx)| cycle as i32 * regx)
+        .sum()
 }
 
-pub fn print_to_screen(instructions: &Vec<Instruction>) {
-    let mut screen: Grid<char> = Grid::new().with_column_size(CYCLES_PER_ROW);
-    let mut ins_iter = instructions.iter().peekable();
-    let mut regx: i32 = 1;
-    let mut position = 0;
-
-    while position != SCREEN_ROWS * CYCLES_PER_ROW {
-        if ins_iter.peek().is_none() {
-            break;
-        };
-
-        screen.elements.push(get_pixel(regx, position));
-        position += 1;
-
-        let next = ins_iter.next().expect("Should be a value");
-        match next {
-            Instruction::Addx(x) => {
-                screen.elements.push(get_pixel(regx, position));
-                position += 1;
-                regx += x;
-            }
-            Instruction::Noop => continue,
-        }
+/// Renders the CRT output as a `Grid<char>`, one pixel per cycle: lit (`#`) when the sprite,
+/// `sprite_width` wide and centred on `regx`, overlaps the pixel currently being drawn.
+/// The sprite spans `regx - sprite_width / 2 ..= regx + sprite_width / 2`, so an even
+/// `sprite_width` covers `sprite_width + 1` pixels.
+pub fn print_to_screen(instructions: &[Instruction], columns: usize, rows: usize, sprite_width: i32) -> Grid<char> {
+    let mut screen: Grid<char> = Grid::new().with_column_size(columns);
+
+    for (cycle, regx) in CpuIterator::new(instructions).take(columns * rows) {
+        let row_position = (cycle - 1) % columns;
+        screen.elements.push(get_pixel(regx, row_position, sprite_width));
     }
-    println!("{}", screen);
+
+    // Pushing pixels straight onto `elements` bypasses `add_row`, which is what normally
+    // tracks row count, so it has to be set explicitly here.
+    screen.rows = screen.elements.len() / columns;
+
+    screen
 }
 
-fn get_pixel(regx: i32, position: usize) -> char {
-    let row_position = position % CYCLES_PER_ROW;
-    let range = (regx - 1)..=(regx + 1);
-    let position = row_position as i32;
+fn get_pixel(regx: i32, row_position: usize, sprite_width: i32) -> char {
+    let half_width = sprite_width / 2;
+    let range = (regx - half_width)..=(regx + half_width);
 
-    if range.contains(&position) {
+    if range.contains(&(row_position as i32)) {
         return '#';
     }
 
@@ -90,7 +61,7 @@ fn parse_line_to_instruction(line: &str) -> Instruction {
     Instruction::Addx(parse(reg_add))
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
 pub enum Instruction {
     Noop,
     Addx(i32),
@@ -112,6 +83,51 @@ impl Instruction {
     }
 }
 
+/// Drives the Addx/Noop program cycle by cycle, yielding `(cycle, register_x)` for every tick.
+/// The yielded register value is the value held *during* that cycle: an `Addx` only takes effect
+/// once its second and final cycle has been yielded.
+pub struct CpuIterator<'a> {
+    instructions: std::slice::Iter<'a, Instruction>,
+    regx: i32,
+    cycle: usize,
+    in_flight: Option<(i32, i32)>,
+}
+
+impl<'a> CpuIterator<'a> {
+    pub fn new(instructions: &'a [Instruction]) -> Self {
+        CpuIterator {
+            instructions: instructions.iter(),
+            regx: 1,
+            cycle: 0,
+            in_flight: None,
+        }
+    }
+}
+
+impl<'a> Iterator for CpuIterator<'a> {
+    type Item = (usize, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.in_flight.is_none() {
+            let instruction = self.instructions.next()?;
+            self.in_flight = Some((instruction.cycles(), instruction.value()));
+        }
+
+        let (cycles_left, pending_add) = self.in_flight.expect("Should have an in-flight instruction");
+        self.cycle += 1;
+        let value = self.regx;
+
+        if cycles_left == 1 {
+            self.regx += pending_add;
+            self.in_flight = None;
+        } else {
+            self.in_flight = Some((cycles_left - 1, pending_add));
+        }
+
+        Some((self.cycle, value))
+    }
+}
+
 //
 //
 //
@@ -129,24 +145,44 @@ mod tests {
         assert_eq!(Instruction::Addx(-3), parse_line_to_instruction(&s));
     }
 
+    #[test]
+    fn test_cpu_iterator() {
+        let instructions = vec![Instruction::Noop, Instruction::Addx(3), Instruction::Addx(-5)];
+        let ticks: Vec<(usize, i32)> = CpuIterator::new(&instructions).collect();
+
+        assert_eq!(
+            vec![(1, 1), (2, 1), (3, 1), (4, 4), (5, 4)],
+            ticks
+        );
+    }
+
     #[test]
     fn test_get_sum_of_strengths() {
-        let result = get_sum_of_signal_strengths(&parse_instructions("resources/test/10_cathode.txt"));
+        let cycles_to_sample = [20, 60, 100, 140, 180, 220];
+        let result = get_sum_of_signal_strengths(
+            &parse_instructions("resources/test/10_cathode.txt"),
+            &cycles_to_sample,
+        );
         assert_eq!(13140, result);
     }
 
     #[test]
     fn test_get_pixel() {
         let regx = 10;
-        assert_eq!('#', get_pixel(regx, 9));
-        assert_eq!('#', get_pixel(regx, 10));
-        assert_eq!('#', get_pixel(regx, 11));
-        assert_eq!('.', get_pixel(regx, 8));
-        assert_eq!('.', get_pixel(regx, 12));
+        assert_eq!('#', get_pixel(regx, 9, SPRITE_WIDTH));
+        assert_eq!('#', get_pixel(regx, 10, SPRITE_WIDTH));
+        assert_eq!('#', get_pixel(regx, 11, SPRITE_WIDTH));
+        assert_eq!('.', get_pixel(regx, 8, SPRITE_WIDTH));
+        assert_eq!('.', get_pixel(regx, 12, SPRITE_WIDTH));
     }
 
     #[test]
     fn test_print_to_screen() {
-        print_to_screen(&parse_instructions("resources/test/10_cathode.txt"));
+        let instructions = parse_instructions("resources/test/10_cathode.txt");
+        let screen = print_to_screen(&instructions, CYCLES_PER_ROW, SCREEN_ROWS, SPRITE_WIDTH);
+
+        println!("{}", screen);
+        assert_eq!(CYCLES_PER_ROW, screen.columns);
+        assert_eq!(SCREEN_ROWS, screen.rows);
     }
 }
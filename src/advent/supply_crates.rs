@@ -3,15 +3,25 @@ use std::fmt;
 use crate::common::io;
 use crate::common::str;
 
-pub fn process_supplies_plan_from_file(filename: &str) -> String {
+pub fn process_supplies_plan_from_file(filename: &str, crate_order: CrateOrder) -> String {
     let lines = io::read_file_as_vector(filename).expect("Could not read file");
     let (mut supplies, move_start_line) = parse_populate_supply_stacks(&lines).expect("Could not parse supply crates");
 
-    parse_apply_move_commands(&lines[move_start_line..].to_vec(), &mut supplies);
+    parse_apply_move_commands(&lines[move_start_line..].to_vec(), &mut supplies, crate_order);
 
     supplies.get_top_of_stacks()
 }
 
+/// Which CrateMover model is doing the lifting.
+///
+/// The 9000 moves crates one at a time, reversing their order on the destination stack.
+/// The 9001 lifts multiple crates at once, so their relative order is preserved.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CrateOrder {
+    Reversed,
+    Preserved,
+}
+
 fn parse_populate_supply_stacks(lines: &Vec<String>) -> Option<(Supplies, usize)> {
     for (i, l) in lines.iter().enumerate() {
         if l.is_empty() {
@@ -25,10 +35,14 @@ fn parse_populate_supply_stacks(lines: &Vec<String>) -> Option<(Supplies, usize)
     None
 }
 
-fn parse_apply_move_commands(lines: &Vec<String>, stacks: &mut Supplies) {
+fn parse_apply_move_commands(lines: &Vec<String>, stacks: &mut Supplies, crate_order: CrateOrder) {
     for l in lines {
         let mv = Move::from_line(l);
-        stacks.move_crates(&mv);
+
+        match crate_order {
+            CrateOrder::Reversed => stacks.move_crates(&mv),
+            CrateOrder::Preserved => stacks.move_crates_preserving_order(&mv),
+        }
     }
 }
 
@@ -104,6 +118,22 @@ impl Supplies {
         }
     }
 
+    // CrateMover 9001 style move: lift `amount` crates off `from` as a single block
+    // and push them onto `to` without reversing their order.
+    fn move_crates_preserving_order(&mut self, crate_move: &Move) {
+        println!("{}", crate_move);
+
+        let amount = crate_move.amount as usize;
+        let lifted = {
+            let from_stack = self.stacks.get_mut(crate_move.from).expect("No stack at index");
+            let split_at = from_stack.len() - amount;
+            from_stack.split_off(split_at)
+        };
+
+        let to_stack = self.stacks.get_mut(crate_move.to).expect("No stack at index");
+        to_stack.extend(lifted);
+    }
+
     fn get_top_of_stacks(&self) -> String {
         let mut tops = Vec::<char>::new();
 
@@ -171,6 +201,26 @@ mod tests {
         assert_eq!(vec!['B', 'C', 'C'], *st.stacks.get(1).unwrap());
     }
 
+    #[test]
+    fn test_move_crates_preserving_order() {
+        let mut st = test_stacks();
+        // Distinct crates on top, so a reversed move would be visible.
+        st.stacks[0] = vec!['A', 'C', 'D'];
+
+        let mv = Move { amount: 2, from: 0, to: 1 };
+        st.move_crates_preserving_order(&mv);
+
+        // The two lifted crates ('C', 'D' from index 1 and 2) keep their relative order,
+        // unlike `move_crates` which would pop-and-push them in reverse.
+        assert_eq!(vec!['A'], *st.stacks.get(0).unwrap());
+        assert_eq!(vec!['B', 'C', 'D'], *st.stacks.get(1).unwrap());
+
+        let mv = Move { amount: 1, from: 1, to: 0 };
+        st.move_crates_preserving_order(&mv);
+        assert_eq!(vec!['A', 'D'], *st.stacks.get(0).unwrap());
+        assert_eq!(vec!['B', 'C'], *st.stacks.get(1).unwrap());
+    }
+
     #[test]
     fn test_parse_move() {
         let mv_str = "move 1 from 2 to 1";
@@ -201,8 +251,14 @@ mod tests {
 
     #[test]
     fn test_process_supplies_plan_from_file() {
-        let top_crates = process_supplies_plan_from_file("resources/test/05_supplies.txt");
+        let top_crates = process_supplies_plan_from_file("resources/test/05_supplies.txt", CrateOrder::Reversed);
         assert_eq!("CMZ", top_crates);
     }
 
+    #[test]
+    fn test_process_supplies_plan_from_file_preserving_order() {
+        let top_crates = process_supplies_plan_from_file("resources/test/05_supplies.txt", CrateOrder::Preserved);
+        assert_eq!("MCD", top_crates);
+    }
+
 }
\ No newline at end of file
@@ -1,4 +1,7 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
 use std::fmt::Display;
+use std::ops::{Index, IndexMut};
 
 /// Grid is a 2-dimensional, row-major ordered array. Column size is fixed, but it can have as many rows as you want.
 /// Rows are added with the `add_row` function.
@@ -44,6 +47,60 @@ impl<T: Clone> Grid<T> {
         self.rows += 1;
     }
 
+    /// Build a grid from column-major input, i.e. `columns[x][y]` rather than `rows[y][x]`.
+    pub fn from_columns(columns: Vec<Vec<T>>) -> Self {
+        let mut grid = Grid::<T>::new();
+
+        if columns.is_empty() {
+            return grid;
+        }
+
+        let rows = columns[0].len();
+        if columns.iter().any(|column| column.len() != rows) {
+            eprintln!("Columns do not all have the same length.");
+            return grid;
+        }
+
+        for y in 0..rows {
+            let row = columns.iter().map(|column| column[y].clone()).collect();
+            grid.add_row(row);
+        }
+
+        grid
+    }
+
+    /// Append a new column to the right of the grid, bumping `self.columns` by one.
+    pub fn push_column(&mut self, column: Vec<T>) {
+        if self.columns == 0 {
+            self.rows = column.len();
+            self.columns = 1;
+            self.elements = column;
+            return;
+        }
+
+        self.insert_column_at(self.columns, column.into_iter());
+    }
+
+    /// Insert a new column at position `x`, shifting columns from `x` onward to the right and
+    /// bumping `self.columns` by one.
+    pub fn insert_column_at(&mut self, x: usize, column: impl Iterator<Item = T>) {
+        let values: Vec<T> = column.collect();
+
+        if values.len() != self.rows {
+            eprintln!("Column length does not equal row length of grid.");
+            return;
+        }
+
+        let old_columns = self.columns;
+
+        for y in (0..self.rows).rev() {
+            let insert_at = y * old_columns + x;
+            self.elements.insert(insert_at, values[y].clone());
+        }
+
+        self.columns += 1;
+    }
+
     pub fn get_row(&self, row: usize) -> Option<Vec<T>> {
         if row >= self.rows {
             return None;
@@ -152,12 +209,164 @@ impl<T: Clone> Grid<T> {
         points
     }
 
+    pub fn get_diagonal_points(&self, point: &Point) -> Vec<Point> {
+        let mut points = Vec::<Point>::new();
+
+        if point.x >= self.columns || point.y >= self.rows {
+            return points;
+        }
+
+        let is_in_bounds = |p: Point| p.x < self.columns && p.y < self.rows;
+
+        for i in -1..=1 {
+            for j in -1..=1 {
+                // only the four corners: both axes must move
+                if i == 0 || j == 0 {
+                    continue;
+                }
+
+                if (point.x as isize + i < 0) || (point.y as isize + j < 0) {
+                    continue;
+                }
+
+                let p = Point {
+                    x: (point.x as isize + i) as usize,
+                    y: (point.y as isize + j) as usize,
+                };
+                if is_in_bounds(p) {
+                    points.push(p);
+                }
+            }
+        }
+
+        points
+    }
+
+    /// The full Moore neighbourhood: the 4-neighbourhood from `get_adjacent_points` plus the
+    /// four diagonal corners from `get_diagonal_points`.
+    pub fn get_all_neighbours(&self, point: &Point) -> Vec<Point> {
+        let mut points = self.get_adjacent_points(point);
+        points.extend(self.get_diagonal_points(point));
+        points
+    }
+
     pub fn is_edge_node(&self, point: &Point) -> bool {
         (point.x == 0 || point.x == self.columns - 1) || (point.y == 0 || point.y == self.rows - 1)
     }
 }
 
+/// Chebyshev distance (king-move distance): the number of king moves to get from `a` to `b`,
+/// i.e. the greater of the x and y distances.
+pub fn chebyshev_distance(a: &Point, b: &Point) -> usize {
+    let dx = a.x.abs_diff(b.x);
+    let dy = a.y.abs_diff(b.y);
+
+    dx.max(dy)
+}
+
+impl<T: Copy + Into<u32>> Grid<T> {
+    /// Dijkstra's algorithm over the grid, where each cell's value is the cost to *enter* it.
+    ///
+    /// Search state is `(Point, Direction, run_length)` rather than just `Point`, so that the
+    /// path can be constrained to move in straight runs: it may continue straight only while
+    /// `run_length < max_run`, and may turn only once `run_length >= min_run`. Reversing
+    /// direction is never allowed. The goal is reached when the popped node is at `goal` *and*
+    /// `run_length >= min_run`. Returns `None` when no legal path exists.
+    pub fn shortest_path(&self, start: Point, goal: Point, min_run: usize, max_run: usize) -> Option<u32> {
+        let mut frontier = BinaryHeap::new();
+        let mut best_cost: HashMap<(Point, Option<Direction>, usize), u32> = HashMap::new();
+
+        frontier.push(Reverse((0u32, start, None::<Direction>, 0usize)));
+        best_cost.insert((start, None, 0), 0);
+
+        while let Some(Reverse((cost, point, direction, run_length))) = frontier.pop() {
+            if point == goal && run_length >= min_run {
+                return Some(cost);
+            }
+
+            if best_cost
+                .get(&(point, direction, run_length))
+                .is_some_and(|&known| known < cost)
+            {
+                continue;
+            }
+
+            for next_direction in Direction::ALL {
+                if let Some(direction) = direction {
+                    if next_direction == direction.opposite() {
+                        continue;
+                    }
+                    if next_direction == direction && run_length >= max_run {
+                        continue;
+                    }
+                    if next_direction != direction && run_length < min_run {
+                        continue;
+                    }
+                }
+
+                let next_run = if direction == Some(next_direction) { run_length + 1 } else { 1 };
+
+                let Some(next_point) = self.step(point, next_direction) else { continue };
+                let Some(&enter_cost) = self.get_element(&next_point) else { continue };
+
+                let next_cost = cost + enter_cost.into();
+                let key = (next_point, Some(next_direction), next_run);
+
+                if best_cost.get(&key).is_none_or(|&known| next_cost < known) {
+                    best_cost.insert(key, next_cost);
+                    frontier.push(Reverse((next_cost, next_point, Some(next_direction), next_run)));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn step(&self, point: Point, direction: Direction) -> Option<Point> {
+        let (dx, dy) = direction.offset();
+        let x = point.x as isize + dx;
+        let y = point.y as isize + dy;
+
+        if x < 0 || y < 0 || x as usize >= self.columns || y as usize >= self.rows {
+            return None;
+        }
+
+        Some(Point { x: x as usize, y: y as usize })
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Ord, PartialOrd, Hash)]
+pub enum Direction {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+impl Direction {
+    const ALL: [Direction; 4] = [Direction::Right, Direction::Down, Direction::Left, Direction::Up];
+
+    fn opposite(&self) -> Direction {
+        match self {
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Up => Direction::Down,
+        }
+    }
+
+    fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Up => (0, -1),
+        }
+    }
+}
+
 #[derive(Eq, PartialEq, Clone, Copy, Debug, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point {
     pub x: usize,
     pub y: usize,
@@ -194,6 +403,105 @@ impl<T: PartialEq> PartialEq for Grid<T> {
     }
 }
 
+/// Indexing by `Point`, `(x, y)` or flat `usize`, reusing the same `columns * y + x` arithmetic
+/// as `get_element`/`get_element_mut`. Panics on out-of-bounds access, the same way `Vec` does.
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        assert!(point.x < self.columns && point.y < self.rows, "Point out of bounds");
+        &self.elements[self.columns * point.y + point.x]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        assert!(point.x < self.columns && point.y < self.rows, "Point out of bounds");
+        &mut self.elements[self.columns * point.y + point.x]
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(x < self.columns && y < self.rows, "Point out of bounds");
+        &self.elements[self.columns * y + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        assert!(x < self.columns && y < self.rows, "Point out of bounds");
+        &mut self.elements[self.columns * y + x]
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.elements[index]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.elements[index]
+    }
+}
+
+/// Serializes as `{ columns, rows, elements }`. Deserializing validates that
+/// `elements.len() == columns * rows`, rejecting malformed input with a serde error
+/// rather than producing a `Grid` whose stride arithmetic would panic later.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct GridData<T> {
+    columns: usize,
+    rows: usize,
+    elements: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T: Clone + serde::Serialize> serde::Serialize for Grid<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        GridData {
+            columns: self.columns,
+            rows: self.rows,
+            elements: self.elements.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Clone + serde::Deserialize<'de>> serde::Deserialize<'de> for Grid<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let data = GridData::<T>::deserialize(deserializer)?;
+
+        if data.elements.len() != data.columns * data.rows {
+            return Err(serde::de::Error::custom(format!(
+                "grid elements length {} does not match columns ({}) * rows ({})",
+                data.elements.len(),
+                data.columns,
+                data.rows
+            )));
+        }
+
+        Ok(Grid {
+            elements: data.elements,
+            columns: data.columns,
+            rows: data.rows,
+        })
+    }
+}
+
 //
 //
 //
@@ -249,6 +557,58 @@ mod tests {
         assert_eq!(None, grid.get_column(20));
     }
 
+    #[test]
+    fn test_from_columns() {
+        let grid = Grid::from_columns(vec![
+            vec![0, 1, 8, 99, 9],
+            vec![0, 3, 7, 2, 20],
+            vec![1, 1, 1, 1, 61],
+            vec![5, 7, 10, 12, 2],
+        ]);
+
+        assert_eq!(get_test_grid(), grid);
+    }
+
+    #[test]
+    fn test_from_columns_rejects_mismatched_column_lengths() {
+        let grid = Grid::from_columns(vec![vec![1, 2], vec![10, 20, 30]]);
+
+        assert_eq!(0, grid.rows);
+        assert_eq!(0, grid.columns);
+    }
+
+    #[test]
+    fn test_push_column() {
+        let mut grid = get_test_grid();
+        grid.push_column(vec![50, 51, 52, 53, 54]);
+
+        assert_eq!(5, grid.columns);
+        assert_eq!(vec![0, 0, 1, 5, 50], grid.get_row(0).unwrap());
+        assert_eq!(vec![9, 20, 61, 2, 54], grid.get_row(4).unwrap());
+        assert_eq!(vec![50, 51, 52, 53, 54], grid.get_column(4).unwrap());
+    }
+
+    #[test]
+    fn test_push_column_onto_empty_grid() {
+        let mut grid = Grid::<u8>::new();
+        grid.push_column(vec![1, 2, 3]);
+
+        assert_eq!(1, grid.columns);
+        assert_eq!(3, grid.rows);
+        assert_eq!(vec![1, 2, 3], grid.get_column(0).unwrap());
+    }
+
+    #[test]
+    fn test_insert_column_at() {
+        let mut grid = get_test_grid();
+        grid.insert_column_at(1, vec![50, 51, 52, 53, 54].into_iter());
+
+        assert_eq!(5, grid.columns);
+        assert_eq!(vec![0, 50, 0, 1, 5], grid.get_row(0).unwrap());
+        assert_eq!(vec![9, 54, 20, 61, 2], grid.get_row(4).unwrap());
+        assert_eq!(vec![50, 51, 52, 53, 54], grid.get_column(1).unwrap());
+    }
+
     #[test]
     fn test_get_inner_grid() {
         let grid = get_test_grid();
@@ -320,6 +680,135 @@ mod tests {
     // grid.add_row(vec![99, 2, 1, 12]);
     // grid.add_row(vec![9, 20, 61, 2]);
 
+    #[test]
+    fn test_get_diagonal_points() {
+        let grid = get_test_grid();
+        assert_eq!(
+            grid.get_diagonal_points(&Point { x: 0, y: 0 }),
+            vec![Point { x: 1, y: 1 }]
+        );
+        assert_eq!(
+            grid.get_diagonal_points(&Point { x: 3, y: 4 }),
+            vec![Point { x: 2, y: 3 }]
+        );
+
+        let mut expected = vec![
+            Point { x: 1, y: 2 },
+            Point { x: 1, y: 4 },
+            Point { x: 3, y: 2 },
+            Point { x: 3, y: 4 },
+        ];
+        expected.sort();
+        let mut actual = grid.get_diagonal_points(&Point { x: 2, y: 3 });
+        actual.sort();
+        assert_eq!(actual, expected);
+
+        assert_eq!(
+            Vec::<Point>::new(),
+            grid.get_diagonal_points(&Point { x: 4, y: 3 })
+        );
+    }
+
+    #[test]
+    fn test_get_all_neighbours() {
+        let grid = get_test_grid();
+
+        let mut expected = vec![
+            Point { x: 1, y: 2 },
+            Point { x: 1, y: 3 },
+            Point { x: 1, y: 4 },
+            Point { x: 2, y: 2 },
+            Point { x: 2, y: 4 },
+            Point { x: 3, y: 2 },
+            Point { x: 3, y: 3 },
+            Point { x: 3, y: 4 },
+        ];
+        expected.sort();
+        let mut actual = grid.get_all_neighbours(&Point { x: 2, y: 3 });
+        actual.sort();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_chebyshev_distance() {
+        assert_eq!(0, chebyshev_distance(&Point { x: 2, y: 2 }, &Point { x: 2, y: 2 }));
+        assert_eq!(3, chebyshev_distance(&Point { x: 0, y: 0 }, &Point { x: 3, y: 1 }));
+        assert_eq!(3, chebyshev_distance(&Point { x: 3, y: 1 }, &Point { x: 0, y: 0 }));
+        assert_eq!(4, chebyshev_distance(&Point { x: 1, y: 5 }, &Point { x: 4, y: 1 }));
+    }
+
+    #[test]
+    fn test_index_by_point() {
+        let mut grid = get_test_grid();
+
+        assert_eq!(7, grid[Point { x: 3, y: 1 }]);
+
+        grid[Point { x: 3, y: 1 }] = 100;
+        assert_eq!(100, grid[Point { x: 3, y: 1 }]);
+    }
+
+    #[test]
+    fn test_index_by_xy_tuple() {
+        let mut grid = get_test_grid();
+
+        assert_eq!(7, grid[(3, 1)]);
+
+        grid[(3, 1)] = 100;
+        assert_eq!(100, grid[(3, 1)]);
+    }
+
+    #[test]
+    fn test_index_by_flat_index() {
+        let mut grid = get_test_grid();
+
+        assert_eq!(7, grid[7]);
+
+        grid[7] = 100;
+        assert_eq!(100, grid[7]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_by_point_out_of_bounds_panics() {
+        let grid = get_test_grid();
+        let _ = grid[Point { x: 99, y: 99 }];
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_by_point_x_out_of_bounds_panics() {
+        // x is out of bounds, but the flat offset (columns * y + x) would otherwise land
+        // inside the next row's elements instead of panicking.
+        let grid = get_test_grid();
+        let _ = grid[Point { x: 4, y: 0 }];
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_by_xy_tuple_out_of_bounds_panics() {
+        let grid = get_test_grid();
+        let _ = grid[(4, 0)];
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serialize_deserialize_grid() {
+        let grid = get_test_grid();
+
+        let json = serde_json::to_string(&grid).unwrap();
+        let round_tripped: Grid<u8> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(grid, round_tripped);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_deserialize_grid_rejects_mismatched_length() {
+        let json = r#"{"columns":4,"rows":2,"elements":[0,0,1,5]}"#;
+
+        assert!(serde_json::from_str::<Grid<u8>>(json).is_err());
+    }
+
     #[test]
     fn test_is_edge_node() {
         let grid = get_test_grid(); 
@@ -332,4 +821,52 @@ mod tests {
         assert!(!grid.is_edge_node(&Point { x: 1, y: 1 }));
         assert!(!grid.is_edge_node(&Point { x: 5, y: 5 }));
     }
+
+    fn get_path_test_grid() -> Grid<u8> {
+        let rows = [
+            "2413432311323",
+            "3215453535623",
+            "3255245654254",
+            "3446585845452",
+            "4546657867536",
+            "1438598798454",
+            "4457876987766",
+            "3637877979653",
+            "4654967986887",
+            "4564679986453",
+            "1224686865563",
+            "2546548887735",
+            "4322674655533",
+        ];
+
+        let mut grid = Grid::<u8>::new();
+        for row in rows {
+            grid.add_row(row.chars().map(|c| c.to_digit(10).unwrap() as u8).collect());
+        }
+
+        grid
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let grid = get_path_test_grid();
+        let start = Point { x: 0, y: 0 };
+        let goal = Point { x: grid.columns - 1, y: grid.rows - 1 };
+
+        // No minimum run, may turn after at most 3 steps straight (regular crucible).
+        assert_eq!(Some(102), grid.shortest_path(start, goal, 0, 3));
+
+        // Must go at least 4 steps straight before turning or stopping, at most 10 (ultra crucible).
+        assert_eq!(Some(94), grid.shortest_path(start, goal, 4, 10));
+    }
+
+    #[test]
+    fn test_shortest_path_no_legal_path() {
+        let grid = get_path_test_grid();
+        let start = Point { x: 0, y: 0 };
+        let goal = Point { x: grid.columns - 1, y: grid.rows - 1 };
+
+        // A minimum run longer than the grid itself can never be satisfied at the goal.
+        assert_eq!(None, grid.shortest_path(start, goal, 100, 100));
+    }
 }